@@ -1,13 +1,15 @@
 use clap::{ArgAction, Parser};
+use globset::{Glob, GlobSet, GlobSetBuilder};
+use ignore::{WalkBuilder, WalkState};
 use regex::Regex;
 use serde::Serialize;
-use std::collections::BTreeMap;
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, BTreeSet};
 use std::ffi::OsStr;
 use std::fs;
 use std::io;
 use std::path::{Path, PathBuf};
-use std::process::Command;
-use walkdir::{DirEntry, WalkDir};
+use std::sync::mpsc;
 
 const INDENT_SPACES: usize = 5;
 
@@ -42,6 +44,19 @@ struct Args {
     #[arg(long, action = ArgAction::SetTrue)]
     include_binaries: bool,
 
+    /// Restrict the tree to these named file types (repeatable, e.g. --type rust --type docs);
+    /// secret-like names (unless --hide-secrets) and --include-binaries still admit other files
+    #[arg(long = "type")]
+    r#type: Vec<String>,
+
+    /// Exclude these named file types (repeatable)
+    #[arg(long = "type-not")]
+    type_not: Vec<String>,
+
+    /// Define or extend a named file type as `name:glob` (repeatable, e.g. --type-add 'proto:*.proto')
+    #[arg(long = "type-add")]
+    type_add: Vec<String>,
+
     /// Maximum depth (number of path segments after the root)
     #[arg(long)]
     max_depth: Option<usize>,
@@ -57,6 +72,26 @@ struct Args {
     /// Print JSON instead of a text tree
     #[arg(long, action = ArgAction::SetTrue)]
     json: bool,
+
+    /// Show file sizes, aggregated directory sizes, and estimated token counts inline
+    #[arg(long, action = ArgAction::SetTrue)]
+    sizes: bool,
+
+    /// Sort directories and files largest-first instead of alphabetically; also decides which
+    /// files --max-files keeps (JSON output keeps directories alphabetical, files largest-first)
+    #[arg(long, action = ArgAction::SetTrue)]
+    sort_by_size: bool,
+
+    /// Extra include/exclude glob on the root-relative path (repeatable; prefix with `!` to force-include)
+    #[arg(long)]
+    glob: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct FileEntry {
+    name: String,
+    size_bytes: u64,
+    est_tokens: u64,
 }
 
 #[derive(Debug, Serialize)]
@@ -65,7 +100,9 @@ struct TreeNode {
     #[serde(skip_serializing_if = "BTreeMap::is_empty")]
     dirs: BTreeMap<String, TreeNode>,
     #[serde(skip_serializing_if = "Vec::is_empty")]
-    files: Vec<String>,
+    files: Vec<FileEntry>,
+    size_bytes: u64,
+    est_tokens: u64,
 }
 
 impl TreeNode {
@@ -74,6 +111,8 @@ impl TreeNode {
             name,
             dirs: BTreeMap::new(),
             files: Vec::new(),
+            size_bytes: 0,
+            est_tokens: 0,
         }
     }
 }
@@ -95,28 +134,45 @@ fn main() -> io::Result<()> {
     };
     let root = git_root.clone().unwrap_or_else(|| start.clone());
 
-    // Collect files (Git-aware first, else filesystem walk)
-    let mut files = if git_root.is_some() && !args.no_git {
-        list_files_git(&root, args.include_ignored, !args.hide_secrets)
-            .unwrap_or_else(|_| list_files_fs(&root))
-    } else {
-        list_files_fs(&root)
-    };
+    // `.treeforaiignore` plus inline `--glob` patterns (root-relative globs), last match wins
+    let overrides = build_override_matcher(&root, &args.glob);
+
+    // Collect files via a single gitignore-aware walk (works with or without a repo present)
+    let mut files = list_files(&root, args.include_ignored, !args.hide_secrets, &overrides);
 
-    // Relevance filter
+    // Named --type/--type-not filter (built-ins + any --type-add extensions)
+    let type_registry = build_type_registry(&args.type_add);
+    let type_globset =
+        (!args.r#type.is_empty()).then(|| compile_globset(&type_registry, &args.r#type));
+    let type_not_globset =
+        (!args.type_not.is_empty()).then(|| compile_globset(&type_registry, &args.type_not));
+
+    // Relevance filter, with `.treeforaiignore`/`--glob` overrides taking the final say
     files.retain(|p| {
-        is_relevant_path(
+        let relevant = is_relevant_path(
+            &root,
             p,
             &FilterOptions {
                 include_assets: args.include_assets,
                 include_binaries: args.include_binaries,
                 hide_secrets: args.hide_secrets,
+                type_globset: type_globset.as_ref(),
+                type_not_globset: type_not_globset.as_ref(),
             },
-        )
+        );
+        match override_decision(&overrides, &root, p) {
+            Some(include) => include,
+            None => relevant,
+        }
     });
 
-    // Deterministic ordering and optional file cap
-    files.sort_by(|a, b| a.cmp(b));
+    // Deterministic ordering, so --max-files caps the set --sort-by-size actually asked for
+    // (otherwise the largest files could be truncated away before they ever reach the tree)
+    if args.sort_by_size {
+        files.sort_by_key(|p| Reverse(fs::metadata(p).map(|m| m.len()).unwrap_or(0)));
+    } else {
+        files.sort();
+    }
     if let Some(max) = args.max_files {
         if files.len() > max {
             files.truncate(max);
@@ -154,7 +210,13 @@ fn main() -> io::Result<()> {
         out.push_str("- All paths are relative to the root above.\n");
         out.push_str("- File contents are not included; ask if more context is needed.\n\n");
     }
-    out.push_str(&render_tree_text(&mut tree, INDENT_SPACES, args.max_depth));
+    out.push_str(&render_tree_text(
+        &mut tree,
+        INDENT_SPACES,
+        args.max_depth,
+        args.sizes,
+        args.sort_by_size,
+    ));
     if !out.ends_with('\n') {
         out.push('\n');
     }
@@ -162,131 +224,243 @@ fn main() -> io::Result<()> {
     Ok(())
 }
 
-/// Return the Git root if we're inside a repo, otherwise None.
+/// Return the Git work-tree root if `cwd` is inside a repo, otherwise None.
+/// Uses `gix::discover` (pure-Rust) instead of shelling out to the `git` binary, so this
+/// works in minimal containers/sandboxes that don't have `git` on PATH. File listing already
+/// goes through `ignore::WalkBuilder`, which honors the repo's ignore rules without needing
+/// the repository object itself — this is the last place the tool depended on Git as a
+/// subprocess.
 fn detect_git_root(cwd: &Path) -> Option<PathBuf> {
-    let output = Command::new("git")
-        .args(["rev-parse", "--show-toplevel"])
-        .current_dir(cwd)
-        .output()
-        .ok()?;
-    if !output.status.success() {
-        return None;
-    }
-    let s = String::from_utf8_lossy(&output.stdout).trim().to_string();
-    if s.is_empty() {
-        None
-    } else {
-        Some(PathBuf::from(s))
-    }
+    let repo = gix::discover(cwd).ok()?;
+    repo.work_dir().map(|p| p.to_path_buf())
 }
 
-/// List files from Git (tracked + untracked), optionally include ignored ones.
-/// When `include_secret_names` is true, we also include names that look like secrets (e.g. `.env`),
-/// but still only as names/paths (never contents).
-fn list_files_git(
+/// List files via a single `ignore::WalkBuilder` pass, honoring `.gitignore`, `.ignore`,
+/// nested ignore files, and global/core.excludesFile rules alike whether or not `root` is
+/// inside a Git repo. This replaces the old git-subprocess/WalkDir split with one code path.
+///
+/// When `include_secret_names` is true, names that look like secrets (e.g. `.env`) are
+/// re-admitted even if an ignore rule would otherwise drop them — but still only as
+/// names/paths (never contents).
+///
+/// When `overrides` has a force-include rule, files it matches are pulled in even if
+/// `.gitignore` would otherwise have dropped them from the walk entirely.
+fn list_files(
     root: &Path,
     include_ignored: bool,
     include_secret_names: bool,
-) -> io::Result<Vec<PathBuf>> {
-    let mut paths = Vec::new();
-
-    // Tracked + untracked (excluding ignored)
-    let out = Command::new("git")
-        .args(["ls-files", "--cached", "--others", "--exclude-standard"])
-        .current_dir(root)
-        .output()?;
-    if out.status.success() {
-        let s = String::from_utf8_lossy(&out.stdout);
-        for line in s.lines().filter(|l| !l.trim().is_empty()) {
-            paths.push(root.join(line));
+    overrides: &Option<(GlobSet, Vec<bool>)>,
+) -> Vec<PathBuf> {
+    let mut paths = walk(root, include_ignored);
+
+    // One extra walk (not one per re-admission rule) picks up names `.gitignore` dropped from
+    // the base walk but that secret-name display or an override rule would re-admit.
+    if !include_ignored && (include_secret_names || overrides.is_some()) {
+        for p in walk(root, true) {
+            if paths.contains(&p) {
+                continue;
+            }
+            let admit = (include_secret_names && is_secret_path(&p))
+                || override_decision(overrides, root, &p) == Some(true);
+            if admit {
+                paths.insert(p);
+            }
         }
     }
 
-    // Optionally include ignored (and/or secret-like names)
-    if include_ignored || include_secret_names {
-        let out_ign = Command::new("git")
-            .args(["ls-files", "--ignored", "--exclude-standard"])
-            .current_dir(root)
-            .output()?;
-        if out_ign.status.success() {
-            let s = String::from_utf8_lossy(&out_ign.stdout);
-            for line in s.lines().filter(|l| !l.trim().is_empty()) {
-                let p = root.join(line);
-                if include_ignored || (include_secret_names && is_secret_path(&p)) {
-                    paths.push(p);
+    paths.into_iter().collect()
+}
+
+/// Run one `WalkBuilder` pass rooted at `root`, using the parallel walker so large trees scan
+/// with all available cores. When `include_ignored` is true, ignore files (`.gitignore`,
+/// `.ignore`, global/core.excludesFile) are not applied, surfacing everything. Returns a set
+/// so callers get O(log n) membership checks instead of scanning a growing `Vec`.
+fn walk(root: &Path, include_ignored: bool) -> BTreeSet<PathBuf> {
+    let mut builder = WalkBuilder::new(root);
+    builder
+        .hidden(false)
+        .parents(true)
+        .require_git(false)
+        .ignore(!include_ignored)
+        .git_ignore(!include_ignored)
+        .git_global(!include_ignored)
+        .git_exclude(!include_ignored);
+
+    let (tx, rx) = mpsc::channel::<PathBuf>();
+    builder.build_parallel().run(|| {
+        let tx = tx.clone();
+        Box::new(move |entry| {
+            if let Ok(entry) = entry {
+                if entry.file_type().map(|t| t.is_file()).unwrap_or(false) {
+                    let _ = tx.send(entry.into_path());
                 }
             }
+            WalkState::Continue
+        })
+    });
+    drop(tx);
+    rx.into_iter().collect()
+}
+
+struct FilterOptions<'a> {
+    include_assets: bool,
+    include_binaries: bool,
+    hide_secrets: bool,
+    type_globset: Option<&'a GlobSet>,
+    type_not_globset: Option<&'a GlobSet>,
+}
+
+/// Built-in named type -> glob patterns, modeled on ripgrep's default `--type` table.
+fn builtin_types() -> Vec<(&'static str, &'static [&'static str])> {
+    vec![
+        ("rust", &["*.rs"]),
+        ("py", &["*.py", "*.pyi", "*.ipynb"]),
+        ("js", &["*.js", "*.cjs", "*.mjs", "*.jsx"]),
+        ("ts", &["*.ts", "*.tsx"]),
+        ("web", &["*.html", "*.htm", "*.css", "*.scss", "*.less"]),
+        (
+            "config",
+            &[
+                "*.toml",
+                "*.yaml",
+                "*.yml",
+                "*.ini",
+                "*.cfg",
+                "*.conf",
+                "Dockerfile",
+                "Makefile",
+            ],
+        ),
+        ("docs", &["*.md", "*.rst", "*.adoc", "*.txt"]),
+        ("cpp", &["*.c", "*.h", "*.cpp", "*.hpp", "*.cc", "*.hh"]),
+        ("go", &["*.go"]),
+        ("java", &["*.java", "*.kt", "*.kts"]),
+    ]
+}
+
+/// Build the type registry: built-ins plus any `name:glob` pairs from `--type-add`.
+fn build_type_registry(extra: &[String]) -> BTreeMap<String, Vec<String>> {
+    let mut registry: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    for (name, globs) in builtin_types() {
+        registry
+            .entry(name.to_string())
+            .or_default()
+            .extend(globs.iter().map(|s| s.to_string()));
+    }
+    for spec in extra {
+        match spec.split_once(':') {
+            Some((name, glob)) => registry
+                .entry(name.to_string())
+                .or_default()
+                .push(glob.to_string()),
+            None => {
+                eprintln!("invalid --type-add `{spec}`, expected `name:glob`");
+                std::process::exit(2);
+            }
         }
     }
-
-    // Deduplicate & sort
-    paths.sort();
-    paths.dedup();
-    Ok(paths)
+    registry
 }
 
-/// Filesystem fallback when Git isn't available (prunes well-known noisy folders)
-fn list_files_fs(root: &Path) -> Vec<PathBuf> {
-    WalkDir::new(root)
-        .follow_links(false)
-        .into_iter()
-        .filter_entry(|e| fs_dir_allow(e))
-        .filter_map(|e| e.ok())
-        .filter(|e| e.file_type().is_file())
-        .map(|e| e.path().to_path_buf())
-        .collect()
+/// Compile the globs for the given type names into one `GlobSet`.
+fn compile_globset(registry: &BTreeMap<String, Vec<String>>, names: &[String]) -> GlobSet {
+    let mut builder = GlobSetBuilder::new();
+    for name in names {
+        let globs = registry.get(name).unwrap_or_else(|| {
+            eprintln!("unknown file type `{name}` (define it with --type-add)");
+            std::process::exit(2);
+        });
+        for g in globs {
+            match Glob::new(g) {
+                Ok(glob) => {
+                    builder.add(glob);
+                }
+                Err(e) => {
+                    eprintln!("invalid glob `{g}` for type `{name}`: {e}");
+                    std::process::exit(2);
+                }
+            }
+        }
+    }
+    builder.build().unwrap_or_else(|e| {
+        eprintln!("failed to build type filter: {e}");
+        std::process::exit(2);
+    })
 }
 
-/// Directory filter for FS walk: skip dependency/build/cache folders to avoid noise.
-fn fs_dir_allow(e: &DirEntry) -> bool {
-    if e.depth() == 0 {
-        return true;
+/// Build the per-project override matcher from an optional `.treeforaiignore` file at `root`
+/// (one pattern per line, `#` comments; a bare pattern excludes, a `!`-prefixed pattern
+/// force-includes) plus any inline `--glob` patterns appended after it, so CLI flags can
+/// override the file. The last pattern to match a given path wins, mirroring ripgrep's
+/// override semantics. Each pattern is a `globset` glob matched against the whole
+/// root-relative path (`*` also crosses `/`); gitignore's trailing-`/` directory patterns,
+/// leading-`/` anchors and bare names matching at any depth are not supported.
+fn build_override_matcher(root: &Path, cli_globs: &[String]) -> Option<(GlobSet, Vec<bool>)> {
+    let mut patterns: Vec<(String, bool)> = Vec::new();
+
+    if let Ok(content) = fs::read_to_string(root.join(".treeforaiignore")) {
+        for line in content.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            match line.strip_prefix('!') {
+                Some(rest) => patterns.push((rest.to_string(), true)),
+                None => patterns.push((line.to_string(), false)),
+            }
+        }
+    }
+
+    for g in cli_globs {
+        match g.strip_prefix('!') {
+            Some(rest) => patterns.push((rest.to_string(), true)),
+            None => patterns.push((g.clone(), false)),
+        }
+    }
+
+    if patterns.is_empty() {
+        return None;
+    }
+
+    let mut builder = GlobSetBuilder::new();
+    let mut includes = Vec::with_capacity(patterns.len());
+    for (pattern, include) in patterns {
+        match Glob::new(&pattern) {
+            Ok(glob) => {
+                builder.add(glob);
+                includes.push(include);
+            }
+            Err(e) => {
+                eprintln!("invalid glob `{pattern}` in .treeforaiignore/--glob: {e}");
+                std::process::exit(2);
+            }
+        }
     }
-    let name = e.file_name().to_string_lossy().to_lowercase();
-    let deny_dirs = [
-        ".git",
-        ".hg",
-        ".svn",
-        "__pycache__",
-        ".cache",
-        ".mypy_cache",
-        ".pytest_cache",
-        ".ruff_cache",
-        ".tox",
-        ".venv",
-        "venv",
-        "env",
-        "node_modules",
-        ".pnpm-store",
-        "dist",
-        "build",
-        "out",
-        ".next",
-        ".nuxt",
-        ".angular",
-        ".parcel-cache",
-        "target",
-        "bin",
-        "obj",
-        ".gradle",
-        ".idea",
-        ".vscode",
-        ".terraform",
-        ".serverless",
-        ".docusaurus",
-    ];
-    !deny_dirs.contains(&name.as_str())
+    let set = builder.build().unwrap_or_else(|e| {
+        eprintln!("failed to build override matcher: {e}");
+        std::process::exit(2);
+    });
+    Some((set, includes))
 }
 
-struct FilterOptions {
-    include_assets: bool,
-    include_binaries: bool,
-    hide_secrets: bool,
+/// Decide whether `overrides` forces `p` in (`Some(true)`), forces it out (`Some(false)`),
+/// or has no opinion (`None`, defer to the relevance heuristic). The last matching pattern wins.
+/// Patterns are root-relative (like ripgrep's `OverrideBuilder`), so `p` is stripped of `root`
+/// before matching.
+fn override_decision(overrides: &Option<(GlobSet, Vec<bool>)>, root: &Path, p: &Path) -> Option<bool> {
+    let (set, includes) = overrides.as_ref()?;
+    let rel = p.strip_prefix(root).unwrap_or(p);
+    set.matches(rel).last().map(|&i| includes[i])
 }
 
 /// Returns true if a path is relevant for an AI-friendly project tree.
 /// Defaults to source/config/text files; assets/binaries are opt-in.
-fn is_relevant_path(p: &Path, opts: &FilterOptions) -> bool {
+/// `root` is used to make `--type`/`--type-not` glob matching root-relative (so extensionless
+/// type globs like `Dockerfile`/`Makefile` can match exactly instead of never matching the
+/// full absolute path).
+fn is_relevant_path(root: &Path, p: &Path, opts: &FilterOptions) -> bool {
+    let rel = p.strip_prefix(root).unwrap_or(p);
+
     if let Some(name) = p.file_name().and_then(OsStr::to_str) {
         let lower = name.to_lowercase();
 
@@ -309,6 +483,14 @@ fn is_relevant_path(p: &Path, opts: &FilterOptions) -> bool {
         }
     }
 
+    // --type-not wins over every rule below: drop matching files outright
+    // (only a `.treeforaiignore`/`--glob` force-include in main can still re-admit them)
+    if let Some(set) = opts.type_not_globset {
+        if set.is_match(rel) {
+            return false;
+        }
+    }
+
     // If secrets are not hidden, show secret-like files by name
     if !opts.hide_secrets && is_secret_path(p) {
         return true;
@@ -318,34 +500,14 @@ fn is_relevant_path(p: &Path, opts: &FilterOptions) -> bool {
         return true;
     }
 
-    // Relevance by extension / special filenames
-    let relevant_exts = [
-        // docs/config
-        "md","rst","adoc","txt","json","jsonc","yaml","yml","toml","ini","cfg","conf","env","properties",
-        // web
-        "html","htm","css","scss","less",
-        // code
-        "rs","py","pyi","ipynb",
-        "js","cjs","mjs","jsx","ts","tsx",
-        "sh","bash","zsh","ps1","bat","cmd",
-        "go","java","kt","kts",
-        "c","h","cpp","hpp","cc","hh",
-        "cs","vb","php","rb","swift","scala","erl","ex","exs",
-        "sql","prisma","graphql","gql",
-        "gradle","groovy","tf","sln","csproj","fsproj","vbproj","vcxproj",
-        "editorconfig","gitattributes","gitignore","eslintignore","prettierignore",
-    ];
-    let asset_exts = [
-        "png","jpg","jpeg","gif","svg","webp","ico","bmp","tiff",
-        "mp3","wav","flac","mp4","mov","mkv","avi",
-        "woff","woff2","eot","ttf","otf","pdf",
-        "zip","tar","gz","tgz","bz2","7z","rar",
-    ];
+    // --type restricts the tree to matching types, replacing the extension heuristic below
+    if let Some(set) = opts.type_globset {
+        return set.is_match(rel);
+    }
 
     // Dockerfile / Makefile without extension
     if let Some(stem) = p.file_name().and_then(OsStr::to_str) {
-        let special = ["Dockerfile", "Makefile", "dockerfile", "Dockerfile.dev"];
-        if special.contains(&stem) {
+        if SPECIAL_FILENAMES.contains(&stem) {
             return true;
         }
     }
@@ -356,10 +518,10 @@ fn is_relevant_path(p: &Path, opts: &FilterOptions) -> bool {
         .map(|s| s.to_lowercase());
 
     if let Some(e) = &ext {
-        if relevant_exts.contains(&e.as_str()) {
+        if RELEVANT_EXTS.contains(&e.as_str()) {
             return true;
         }
-        if opts.include_assets && asset_exts.contains(&e.as_str()) {
+        if opts.include_assets && ASSET_EXTS.contains(&e.as_str()) {
             return true;
         }
     }
@@ -367,6 +529,45 @@ fn is_relevant_path(p: &Path, opts: &FilterOptions) -> bool {
     false
 }
 
+// Relevance by extension / special filenames
+const RELEVANT_EXTS: &[&str] = &[
+    // docs/config
+    "md","rst","adoc","txt","json","jsonc","yaml","yml","toml","ini","cfg","conf","env","properties",
+    // web
+    "html","htm","css","scss","less",
+    // code
+    "rs","py","pyi","ipynb",
+    "js","cjs","mjs","jsx","ts","tsx",
+    "sh","bash","zsh","ps1","bat","cmd",
+    "go","java","kt","kts",
+    "c","h","cpp","hpp","cc","hh",
+    "cs","vb","php","rb","swift","scala","erl","ex","exs",
+    "sql","prisma","graphql","gql",
+    "gradle","groovy","tf","sln","csproj","fsproj","vbproj","vcxproj",
+    "editorconfig","gitattributes","gitignore","eslintignore","prettierignore",
+];
+const ASSET_EXTS: &[&str] = &[
+    "png","jpg","jpeg","gif","svg","webp","ico","bmp","tiff",
+    "mp3","wav","flac","mp4","mov","mkv","avi",
+    "woff","woff2","eot","ttf","otf","pdf",
+    "zip","tar","gz","tgz","bz2","7z","rar",
+];
+const SPECIAL_FILENAMES: &[&str] = &["Dockerfile", "Makefile", "dockerfile", "Dockerfile.dev"];
+
+/// True if `p` is the kind of file worth estimating a token count for (source/config/text),
+/// as opposed to an asset or binary that happens to have been included.
+fn is_text_relevant(p: &Path) -> bool {
+    if let Some(stem) = p.file_name().and_then(OsStr::to_str) {
+        if SPECIAL_FILENAMES.contains(&stem) {
+            return true;
+        }
+    }
+    p.extension()
+        .and_then(OsStr::to_str)
+        .map(|e| RELEVANT_EXTS.contains(&e.to_lowercase().as_str()))
+        .unwrap_or(false)
+}
+
 /// Heuristic for "secret-like" names (names only; never contents).
 fn is_secret_path(p: &Path) -> bool {
     let fname = p
@@ -383,7 +584,13 @@ fn is_secret_path(p: &Path) -> bool {
     re.is_match(&fname)
 }
 
+/// Estimate a token count from a byte size (rough heuristic: ~4 bytes/token).
+fn estimate_tokens(size_bytes: u64) -> u64 {
+    size_bytes / 4
+}
+
 /// Build a directory tree from a flat list of paths (respects max_depth for files).
+/// Each file is stat'd for its size; `aggregate_sizes` rolls those up into directory totals.
 fn build_tree(paths: &[PathBuf], root: &Path, max_depth: Option<usize>) -> TreeNode {
     let root_name = root
         .file_name()
@@ -404,7 +611,17 @@ fn build_tree(paths: &[PathBuf], root: &Path, max_depth: Option<usize>) -> TreeN
             if is_last {
                 let depth = parts.len();
                 if max_depth.map(|m| depth <= m).unwrap_or(true) {
-                    cur.files.push(name);
+                    let size_bytes = fs::metadata(p).map(|m| m.len()).unwrap_or(0);
+                    let est_tokens = if is_text_relevant(p) {
+                        estimate_tokens(size_bytes)
+                    } else {
+                        0
+                    };
+                    cur.files.push(FileEntry {
+                        name,
+                        size_bytes,
+                        est_tokens,
+                    });
                 }
             } else {
                 let depth = i + 1; // how many parts so far
@@ -418,36 +635,110 @@ fn build_tree(paths: &[PathBuf], root: &Path, max_depth: Option<usize>) -> TreeN
             }
         }
     }
+    aggregate_sizes(&mut tree);
     tree
 }
 
-/// Render the tree with 5‑space indentation. Directories first (sorted), then files (sorted).
-fn render_tree_text(tree: &mut TreeNode, indent: usize, max_depth: Option<usize>) -> String {
+/// Recursively sum file sizes/token estimates into each directory's totals.
+fn aggregate_sizes(node: &mut TreeNode) -> (u64, u64) {
+    let mut size_bytes = 0u64;
+    let mut est_tokens = 0u64;
+    for f in &node.files {
+        size_bytes += f.size_bytes;
+        est_tokens += f.est_tokens;
+    }
+    for child in node.dirs.values_mut() {
+        let (child_size, child_tokens) = aggregate_sizes(child);
+        size_bytes += child_size;
+        est_tokens += child_tokens;
+    }
+    node.size_bytes = size_bytes;
+    node.est_tokens = est_tokens;
+    (size_bytes, est_tokens)
+}
+
+/// Format a byte count the way a human would skim it (e.g. `42.1 KB`).
+fn human_size(bytes: u64) -> String {
+    const KB: f64 = 1024.0;
+    let bytes = bytes as f64;
+    if bytes < KB {
+        format!("{bytes} B")
+    } else if bytes < KB * KB {
+        format!("{:.1} KB", bytes / KB)
+    } else {
+        format!("{:.1} MB", bytes / KB / KB)
+    }
+}
+
+/// Format a token estimate the way a human would skim it (e.g. `~10.8k tok`).
+fn human_tokens(tokens: u64) -> String {
+    if tokens >= 1000 {
+        format!("~{:.1}k tok", tokens as f64 / 1000.0)
+    } else {
+        format!("~{tokens} tok")
+    }
+}
+
+/// Render the tree with 5‑space indentation. Directories first, then files.
+/// When `sort_by_size` is set, both are sorted largest-first instead of alphabetically.
+/// When `show_sizes` is set, sizes (and token estimates for dirs) are appended inline.
+fn render_tree_text(
+    tree: &mut TreeNode,
+    indent: usize,
+    max_depth: Option<usize>,
+    show_sizes: bool,
+    sort_by_size: bool,
+) -> String {
     fn rec(
         n: &mut TreeNode,
         level: usize,
         indent: usize,
         lines: &mut Vec<String>,
         max_depth: Option<usize>,
+        show_sizes: bool,
+        sort_by_size: bool,
     ) {
         if level == 0 {
-            lines.push(format!("{}/", n.name));
+            let suffix = if show_sizes {
+                format!(" ({}, {})", human_size(n.size_bytes), human_tokens(n.est_tokens))
+            } else {
+                String::new()
+            };
+            lines.push(format!("{}/{suffix}", n.name));
         }
         // Directories
-        for (_k, v) in &mut n.dirs {
-            lines.push(format!("{}{}/", " ".repeat(indent * (level + 1)), v.name));
+        let mut dirs: Vec<&mut TreeNode> = n.dirs.values_mut().collect();
+        if sort_by_size {
+            dirs.sort_by_key(|v| Reverse(v.size_bytes));
+        }
+        for v in dirs {
+            let suffix = if show_sizes {
+                format!(" ({}, {})", human_size(v.size_bytes), human_tokens(v.est_tokens))
+            } else {
+                String::new()
+            };
+            lines.push(format!("{}{}/{suffix}", " ".repeat(indent * (level + 1)), v.name));
             if max_depth.map(|m| level + 1 < m).unwrap_or(true) {
-                rec(v, level + 1, indent, lines, max_depth);
+                rec(v, level + 1, indent, lines, max_depth, show_sizes, sort_by_size);
             }
         }
         // Files
-        n.files
-            .sort_unstable_by(|a, b| a.to_lowercase().cmp(&b.to_lowercase()));
+        if sort_by_size {
+            n.files.sort_by_key(|f| Reverse(f.size_bytes));
+        } else {
+            n.files
+                .sort_unstable_by_key(|f| f.name.to_lowercase());
+        }
         for f in &n.files {
-            lines.push(format!("{}{}", " ".repeat(indent * (level + 1)), f));
+            let suffix = if show_sizes {
+                format!("  {}", human_size(f.size_bytes))
+            } else {
+                String::new()
+            };
+            lines.push(format!("{}{}{suffix}", " ".repeat(indent * (level + 1)), f.name));
         }
     }
     let mut lines = Vec::new();
-    rec(tree, 0, indent, &mut lines, max_depth);
+    rec(tree, 0, indent, &mut lines, max_depth, show_sizes, sort_by_size);
     lines.join("\n")
 }